@@ -0,0 +1,274 @@
+//! Async mirror of the blocking driver.
+//!
+//! [`Ft6336Async`] is the counterpart of [`Ft6336`](crate::Ft6336) built on
+//! [`embedded_hal_async::i2c::I2c`], so the controller can be awaited alongside
+//! other peripherals on executors like Embassy instead of polling in a loop.
+
+use crate::{Error, PowerMode, Transform, FT6336_ADDR};
+use embedded_hal_async::i2c::I2c;
+
+use crate::gesture::{Gesture, REG_GESTURE_ENABLE, REG_GESTURE_ID};
+use crate::touch::{PointsIter, REG_TOUCH_COUNT};
+use num_enum::FromPrimitive;
+
+/// FT6336 struct, async variant.
+#[derive(Debug)]
+pub struct Ft6336Async<I2C> {
+    i2c: I2C,
+    transform: Transform,
+}
+
+impl<I2C: I2c> Ft6336Async<I2C> {
+    pub fn new(i2c: I2C) -> Self {
+        Self {
+            i2c,
+            transform: Transform::default(),
+        }
+    }
+
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+
+    /// Switchs to work mode, to avoid rare case that the controller
+    /// is left in factory mode.
+    ///
+    /// This method is safe to call at anytime.
+    pub async fn init(&mut self) -> Result<(), Error> {
+        self.write_u8(0x00, 0x00).await
+    }
+
+    /// Returns chip code.
+    ///
+    /// - FT6236G: 0x00, ?, ?
+    /// - FT6336G: 0x01, ?, ?
+    /// - FT6336U: 0x02, ?, ?
+    /// - FT6426: 0x03, ?, ?
+    pub async fn chip_code(&mut self) -> Result<(u8, u8, u8), Error> {
+        let low = self.read_u8(0xA0).await?;
+        let mid = self.read_u8(0x9F).await?;
+        let high = self.read_u8(0xA3).await?;
+        Ok((low, mid, high))
+    }
+
+    /// Returns app lib version.
+    pub async fn applib_version(&mut self) -> Result<(u8, u8), Error> {
+        let mut buf: [u8; 2] = [0; 2];
+        self.read_buf(0xA1, &mut buf).await?;
+        let low = buf[1];
+        let high = buf[0];
+        Ok((low, high))
+    }
+
+    /// Returns firmware version.
+    pub async fn firmware_version(&mut self) -> Result<u8, Error> {
+        self.read_u8(0xA6).await
+    }
+
+    /// Returns vendor ID.
+    pub async fn vender_id(&mut self) -> Result<u8, Error> {
+        self.read_u8(0xA8).await
+    }
+
+    /// Returns release code ID on custom reference version.
+    pub async fn release_code(&mut self) -> Result<u8, Error> {
+        self.read_u8(0xAF).await
+    }
+
+    /// Sets frequency hopping enable status.
+    ///
+    /// Set true to enable frequency hopping(useful when plugged to a power source).
+    /// But it seems not necessary under most cases.
+    pub async fn set_use_freqency_hopping(&mut self, value: bool) -> Result<(), Error> {
+        match value {
+            true => self.write_u8(0x8B, 0x01).await,
+            false => self.write_u8(0x8B, 0x00).await,
+        }
+    }
+
+    /// Sets the INT pin behavior to generate a pulse when there's new touch event.
+    ///
+    /// In either interrupt mode, the touch released events will not generate an
+    /// iterrupt signal.
+    pub async fn interrupt_by_pulse(&mut self) -> Result<(), Error> {
+        self.write_u8(0xA4, 0x01).await
+    }
+
+    /// Sets the INT pin behavior to drive low when there's new touch event to process.
+    ///
+    /// In either interrupt mode, the touch released events will not generate an
+    /// iterrupt signal.
+    pub async fn interrupt_by_state(&mut self) -> Result<(), Error> {
+        self.write_u8(0xA4, 0x00).await
+    }
+
+    /// Sets whether to automatically enter monitor mode(simpler scan mode, saves energy).
+    pub async fn set_auto_monitor_mode(&mut self, value: bool) -> Result<(), Error> {
+        match value {
+            true => self.write_u8(0x86, 0x01).await,
+            false => self.write_u8(0x86, 0x00).await,
+        }
+    }
+
+    /// Sets the time limit(in second) to enter monitor mode automatically.
+    ///
+    /// Default is 30 seconds. Maximum value is 0x64
+    pub async fn set_auto_monitor_mode_delay(&mut self, value: u8) -> Result<(), Error> {
+        if value > 0x64 {
+            self.write_u8(0x87, 0x64).await
+        } else {
+            self.write_u8(0x87, value).await
+        }
+    }
+
+    /// Sets the scan rate under active mode, in Hertz.
+    ///
+    /// minimum 0x04, maximum 0x14
+    pub async fn set_scan_rate(&mut self, value: u8) -> Result<(), Error> {
+        if value < 0x04 {
+            self.write_u8(0x88, 0x04).await
+        } else if value > 0x14 {
+            self.write_u8(0x88, 0x14).await
+        } else {
+            self.write_u8(0x88, value).await
+        }
+    }
+
+    /// Sets the scan rate under monitor mode, in Hertz.
+    ///
+    /// minimum 0x04, maximum 0x14
+    pub async fn set_monitor_scan_rate(&mut self, value: u8) -> Result<(), Error> {
+        if value < 0x04 {
+            self.write_u8(0x89, 0x04).await
+        } else if value > 0x14 {
+            self.write_u8(0x89, 0x14).await
+        } else {
+            self.write_u8(0x89, value).await
+        }
+    }
+
+    /// Sets touch driver [`PowerMode`].
+    pub async fn set_power_mode(&mut self, value: PowerMode) -> Result<(), Error> {
+        self.write_u8(0xA5, value.into()).await
+    }
+
+    /// Returns the touch detection threshold(`TH_GROUP`, register `0x80`).
+    pub async fn touch_threshold(&mut self) -> Result<u8, Error> {
+        self.read_u8(0x80).await
+    }
+
+    /// Sets the touch detection threshold(`TH_GROUP`, register `0x80`).
+    ///
+    /// Lower values make the panel more sensitive; raise it for thick
+    /// cover-glass so stray capacitance is rejected.
+    pub async fn set_touch_threshold(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x80, value).await
+    }
+
+    /// Returns the noise/filter difference threshold(`TH_DIFF`, register `0x85`).
+    pub async fn filter_coefficient(&mut self) -> Result<u8, Error> {
+        self.read_u8(0x85).await
+    }
+
+    /// Sets the noise/filter difference threshold(`TH_DIFF`, register `0x85`).
+    pub async fn set_filter_coefficient(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x85, value).await
+    }
+
+    /// Sets the coordinate [`Transform`] applied to touch points.
+    ///
+    /// The default is the identity transform. Raw reads via
+    /// [`touches_raw`](Self::touches_raw) are unaffected; only the points
+    /// yielded by [`touch_points_iter`](Self::touch_points_iter) are mapped
+    /// into display space.
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
+    /// Reads current touch count.
+    pub async fn touch_count(&mut self) -> Result<u8, Error> {
+        self.read_u8(REG_TOUCH_COUNT).await
+    }
+
+    /// Reads all current touch information.
+    pub async fn touches_raw(&mut self) -> Result<[u8; 13], Error> {
+        let mut buf: [u8; 13] = [0; 13];
+        self.read_buf(REG_TOUCH_COUNT, &mut buf).await?;
+        Ok(buf)
+    }
+
+    /// Get an iterator over current touch events.
+    pub async fn touch_points_iter(&mut self) -> Result<PointsIter, Error> {
+        let mut buf: [u8; 13] = [0; 13];
+        self.read_buf(REG_TOUCH_COUNT, &mut buf).await?;
+        Ok(PointsIter {
+            data: buf,
+            transform: self.transform,
+        })
+    }
+
+    /// Reads the currently recognized [`Gesture`].
+    ///
+    /// Returns [`Gesture::None`] when no gesture is present or gesture mode is
+    /// disabled.
+    pub async fn gesture(&mut self) -> Result<Gesture, Error> {
+        Ok(Gesture::from_primitive(self.read_u8(REG_GESTURE_ID).await?))
+    }
+
+    /// Sets whether gesture recognition is enabled.
+    pub async fn set_gesture_enable(&mut self, value: bool) -> Result<(), Error> {
+        match value {
+            true => self.write_u8(REG_GESTURE_ENABLE, 0x01).await,
+            false => self.write_u8(REG_GESTURE_ENABLE, 0x00).await,
+        }
+    }
+
+    /// Sets the maximum angle(radian) allowed for a swipe to be recognized.
+    pub async fn set_gesture_radian(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x91, value).await
+    }
+
+    /// Sets the minimum horizontal distance to recognize a left/right swipe.
+    pub async fn set_gesture_offset_left_right(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x92, value).await
+    }
+
+    /// Sets the minimum vertical distance to recognize an up/down swipe.
+    pub async fn set_gesture_offset_up_down(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x93, value).await
+    }
+
+    /// Sets the sampling distance used to track left/right swipes.
+    pub async fn set_gesture_distance_left_right(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x94, value).await
+    }
+
+    /// Sets the sampling distance used to track up/down swipes.
+    pub async fn set_gesture_distance_up_down(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x95, value).await
+    }
+
+    /// Sets the minimum distance threshold to recognize a zoom gesture.
+    pub async fn set_gesture_distance_zoom(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x96, value).await
+    }
+
+    /// Reads one u8 integer.
+    async fn read_u8(&mut self, reg: u8) -> Result<u8, Error> {
+        let mut buf: [u8; 1] = [0; 1];
+
+        match self.i2c.write_read(FT6336_ADDR, &[reg], &mut buf).await {
+            Ok(_) => Ok(buf[0]),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn write_u8(&mut self, reg: u8, value: u8) -> Result<(), Error> {
+        Ok(self.i2c.write(FT6336_ADDR, &[reg, value]).await?)
+    }
+
+    #[inline]
+    async fn read_buf(&mut self, reg: u8, buf: &mut [u8]) -> Result<(), Error> {
+        Ok(self.i2c.write_read(FT6336_ADDR, &[reg], buf).await?)
+    }
+}
@@ -0,0 +1,75 @@
+//! Gesture feature implementation.
+//!
+//! The controller reports a recognized gesture in the `GEST_ID` register, so a
+//! single read yields swipe/zoom events without reconstructing strokes from raw
+//! points. Gesture recognition must be enabled first and tuned with the
+//! detection window registers below; not all variants support every gesture.
+
+use crate::{Error, Ft6336, I2c};
+use num_enum::{FromPrimitive, IntoPrimitive};
+
+pub(crate) const REG_GESTURE_ID: u8 = 0x01;
+pub(crate) const REG_GESTURE_ENABLE: u8 = 0xD0;
+
+/// Recognized gesture, as reported by the `GEST_ID` register.
+#[repr(u8)]
+#[derive(IntoPrimitive, FromPrimitive, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Gesture {
+    MoveUp = 0x10,
+    MoveRight = 0x14,
+    MoveDown = 0x18,
+    MoveLeft = 0x1C,
+    ZoomIn = 0x48,
+    ZoomOut = 0x49,
+    #[num_enum(default)]
+    None = 0x00,
+}
+
+impl<I2C: I2c> Ft6336<I2C> {
+    /// Reads the currently recognized [`Gesture`].
+    ///
+    /// Returns [`Gesture::None`] when no gesture is present or gesture mode is
+    /// disabled.
+    pub fn gesture(&mut self) -> Result<Gesture, Error> {
+        Ok(Gesture::from_primitive(self.read_u8(REG_GESTURE_ID)?))
+    }
+
+    /// Sets whether gesture recognition is enabled.
+    pub fn set_gesture_enable(&mut self, value: bool) -> Result<(), Error> {
+        match value {
+            true => self.write_u8(REG_GESTURE_ENABLE, 0x01),
+            false => self.write_u8(REG_GESTURE_ENABLE, 0x00),
+        }
+    }
+
+    /// Sets the maximum angle(radian) allowed for a swipe to be recognized.
+    pub fn set_gesture_radian(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x91, value)
+    }
+
+    /// Sets the minimum horizontal distance to recognize a left/right swipe.
+    pub fn set_gesture_offset_left_right(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x92, value)
+    }
+
+    /// Sets the minimum vertical distance to recognize an up/down swipe.
+    pub fn set_gesture_offset_up_down(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x93, value)
+    }
+
+    /// Sets the sampling distance used to track left/right swipes.
+    pub fn set_gesture_distance_left_right(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x94, value)
+    }
+
+    /// Sets the sampling distance used to track up/down swipes.
+    pub fn set_gesture_distance_up_down(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x95, value)
+    }
+
+    /// Sets the minimum distance threshold to recognize a zoom gesture.
+    pub fn set_gesture_distance_zoom(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x96, value)
+    }
+}
@@ -0,0 +1,157 @@
+//! Interrupt-pin event source.
+//!
+//! The INT pin is asserted(driven low) when there is a new touch event to
+//! process. [`Ft6336Interrupt`] takes ownership of the driver and an
+//! [`InputPin`] connected to that line and gates register reads on it, so
+//! event-driven firmware only transacts on the bus when there is actually new
+//! data, mirroring how the Linux FT6236 driver keys its reads off the IRQ
+//! instead of continuously scanning.
+//!
+//! The blocking wrapper samples the line level, so it assumes
+//! [`interrupt_by_state`](Ft6336::interrupt_by_state) mode; a momentary pulse
+//! from [`interrupt_by_pulse`](Ft6336::interrupt_by_pulse) would be missed by a
+//! polled read. The async [`Ft6336InterruptAsync`] can wait on either mode via
+//! its [`InterruptMode`].
+
+use crate::touch::PointsIter;
+use crate::{Error, Ft6336, I2c};
+use embedded_hal::digital::InputPin;
+
+/// Configured behavior of the INT pin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InterruptMode {
+    /// A pulse is generated on each new touch event
+    /// (see [`interrupt_by_pulse`](Ft6336::interrupt_by_pulse)).
+    Pulse,
+    /// The line is held asserted while there is data to process
+    /// (see [`interrupt_by_state`](Ft6336::interrupt_by_state)).
+    State,
+}
+
+/// Driver paired with its INT pin.
+///
+/// Assumes [`interrupt_by_state`](Ft6336::interrupt_by_state) mode: these
+/// methods sample the INT line level, so a brief pulse would be missed. Use
+/// [`Ft6336InterruptAsync`] when you need pulse-mode edge waiting.
+#[derive(Debug)]
+pub struct Ft6336Interrupt<I2C, INT> {
+    driver: Ft6336<I2C>,
+    int: INT,
+}
+
+impl<I2C: I2c, INT: InputPin> Ft6336Interrupt<I2C, INT> {
+    /// Wraps `driver` and the `int` pin.
+    ///
+    /// The pin is expected to already be configured to read the INT line; this
+    /// does not program the controller's interrupt mode, use
+    /// [`interrupt_by_state`](Ft6336::interrupt_by_state) for that.
+    pub fn new(driver: Ft6336<I2C>, int: INT) -> Self {
+        Self { driver, int }
+    }
+
+    /// Releases the wrapped driver and INT pin.
+    pub fn destroy(self) -> (Ft6336<I2C>, INT) {
+        (self.driver, self.int)
+    }
+
+    /// Borrows the wrapped driver for direct register access.
+    pub fn driver(&mut self) -> &mut Ft6336<I2C> {
+        &mut self.driver
+    }
+
+    /// Returns whether the INT line is currently asserting new touch data.
+    ///
+    /// The line is active low while there is data to process.
+    pub fn touch_pending(&mut self) -> Result<bool, Error> {
+        self.int.is_low().map_err(|_| Error::Other)
+    }
+
+    /// Reads touch points only if the INT line signals new data.
+    ///
+    /// Returns `None` without touching the bus when no event is pending.
+    pub fn poll_touches(&mut self) -> Result<Option<PointsIter>, Error> {
+        if self.touch_pending()? {
+            Ok(Some(self.driver.touch_points_iter()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Busy-waits for the INT line to assert, then reads the touch points.
+    pub fn wait_for_touch(&mut self) -> Result<PointsIter, Error> {
+        while !self.touch_pending()? {}
+        self.driver.touch_points_iter()
+    }
+}
+
+/// Async driver paired with its INT pin.
+///
+/// Counterpart of [`Ft6336Interrupt`] built on the async driver and an async
+/// [`Wait`](embedded_hal_async::digital::Wait) pin, so `wait_for_touch` yields
+/// to the executor instead of busy-waiting.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct Ft6336InterruptAsync<I2C, INT> {
+    driver: crate::Ft6336Async<I2C>,
+    int: INT,
+    mode: InterruptMode,
+}
+
+#[cfg(feature = "async")]
+impl<I2C, INT> Ft6336InterruptAsync<I2C, INT>
+where
+    I2C: embedded_hal_async::i2c::I2c,
+    INT: InputPin + embedded_hal_async::digital::Wait,
+{
+    /// Wraps the async `driver` and the `int` pin, remembering the configured
+    /// [`InterruptMode`].
+    pub fn new(driver: crate::Ft6336Async<I2C>, int: INT, mode: InterruptMode) -> Self {
+        Self { driver, int, mode }
+    }
+
+    /// Releases the wrapped driver and INT pin.
+    pub fn destroy(self) -> (crate::Ft6336Async<I2C>, INT) {
+        (self.driver, self.int)
+    }
+
+    /// Borrows the wrapped driver for direct register access.
+    pub fn driver(&mut self) -> &mut crate::Ft6336Async<I2C> {
+        &mut self.driver
+    }
+
+    /// Returns whether the INT line is currently asserting new touch data.
+    ///
+    /// Samples the line level, so it is only meaningful in
+    /// [`InterruptMode::State`].
+    pub fn touch_pending(&mut self) -> Result<bool, Error> {
+        self.int.is_low().map_err(|_| Error::Other)
+    }
+
+    /// Reads touch points only if the INT line signals new data.
+    ///
+    /// Returns `None` without touching the bus when no event is pending. Only
+    /// meaningful in [`InterruptMode::State`]; use
+    /// [`wait_for_touch`](Self::wait_for_touch) for [`InterruptMode::Pulse`].
+    pub async fn poll_touches(&mut self) -> Result<Option<PointsIter>, Error> {
+        if self.touch_pending()? {
+            Ok(Some(self.driver.touch_points_iter().await?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Awaits the INT line assertion, then reads the touch points.
+    ///
+    /// In [`InterruptMode::Pulse`] this waits for the falling edge so a
+    /// momentary pulse is caught; in [`InterruptMode::State`] it waits for the
+    /// line to be held low.
+    pub async fn wait_for_touch(&mut self) -> Result<PointsIter, Error> {
+        match self.mode {
+            InterruptMode::Pulse => self.int.wait_for_falling_edge().await,
+            InterruptMode::State => self.int.wait_for_low().await,
+        }
+        .map_err(|_| Error::Other)?;
+        self.driver.touch_points_iter().await
+    }
+}
@@ -5,7 +5,22 @@
 use embedded_hal::i2c::{Error as I2cError, ErrorKind as I2cErrorKind, I2c};
 use num_enum::{FromPrimitive, IntoPrimitive};
 
+mod gesture;
+mod interrupt;
 mod touch;
+mod transform;
+
+pub use interrupt::{Ft6336Interrupt, InterruptMode};
+pub use transform::{Rotation, Transform};
+
+#[cfg(feature = "async")]
+pub use interrupt::Ft6336InterruptAsync;
+
+#[cfg(feature = "async")]
+mod async_impl;
+
+#[cfg(feature = "async")]
+pub use async_impl::Ft6336Async;
 
 const FT6336_ADDR: u8 = 0x38;
 
@@ -48,11 +63,15 @@ pub enum PowerMode {
 #[derive(Debug)]
 pub struct Ft6336<I2C> {
     i2c: I2C,
+    transform: Transform,
 }
 
 impl<I2C: I2c> Ft6336<I2C> {
     pub fn new(i2c: I2C) -> Self {
-        Self { i2c }
+        Self {
+            i2c,
+            transform: Transform::default(),
+        }
     }
 
     pub fn destroy(self) -> I2C {
@@ -181,6 +200,39 @@ impl<I2C: I2c> Ft6336<I2C> {
         self.write_u8(0xA5, value.into())
     }
 
+    /// Returns the touch detection threshold(`TH_GROUP`, register `0x80`).
+    pub fn touch_threshold(&mut self) -> Result<u8, Error> {
+        self.read_u8(0x80)
+    }
+
+    /// Sets the touch detection threshold(`TH_GROUP`, register `0x80`).
+    ///
+    /// Lower values make the panel more sensitive; raise it for thick
+    /// cover-glass so stray capacitance is rejected.
+    pub fn set_touch_threshold(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x80, value)
+    }
+
+    /// Returns the noise/filter difference threshold(`TH_DIFF`, register `0x85`).
+    pub fn filter_coefficient(&mut self) -> Result<u8, Error> {
+        self.read_u8(0x85)
+    }
+
+    /// Sets the noise/filter difference threshold(`TH_DIFF`, register `0x85`).
+    pub fn set_filter_coefficient(&mut self, value: u8) -> Result<(), Error> {
+        self.write_u8(0x85, value)
+    }
+
+    /// Sets the coordinate [`Transform`] applied to touch points.
+    ///
+    /// The default is the identity transform. Raw reads via
+    /// [`touches_raw`](Self::touches_raw) are unaffected; only the points
+    /// yielded by [`touch_points_iter`](Self::touch_points_iter) are mapped
+    /// into display space.
+    pub fn set_transform(&mut self, transform: Transform) {
+        self.transform = transform;
+    }
+
     /// Reads one u8 integer.
     fn read_u8(&mut self, reg: u8) -> Result<u8, Error> {
         let mut buf: [u8; 1] = [0; 1];
@@ -1,12 +1,13 @@
 //! Touch feature implementation.
 //!
-//! Not all variants support the gesture/weight/size(they might be just zeros).
-//! Here only minimum touch detection is implemented.
+//! Each touch slot is decoded into a [`Point`], including the per-touch
+//! `weight` and `area` fields. Note not all variants populate those two
+//! (they might be just zeros), but decoding them is harmless where they are.
 
-use crate::{Error, Ft6336, I2c};
+use crate::{Error, Ft6336, I2c, Transform};
 use num_enum::{FromPrimitive, IntoPrimitive};
 
-const REG_TOUCH_COUNT: u8 = 0x02;
+pub(crate) const REG_TOUCH_COUNT: u8 = 0x02;
 
 /// Point action.
 #[repr(u8)]
@@ -27,28 +28,41 @@ pub struct Point {
     pub action: PointAction,
     pub x: u16,
     pub y: u16,
+    /// Touch pressure/weight. May be zero on variants that don't report it.
+    pub weight: u8,
+    /// Touch area. May be zero on variants that don't report it.
+    pub area: u8,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct PointsIter {
-    data: [u8; 11],
+    pub(crate) data: [u8; 13],
+    pub(crate) transform: Transform,
 }
 
 impl Iterator for PointsIter {
     type Item = Point;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.data[0] > 0 {
-            self.data[0] -= 1;
+        // Mask off the reserved high nibble of TD_STATUS and clamp to the two
+        // slots the buffer holds, so a spurious count can't index out of bounds.
+        let remaining = (self.data[0] & 0x0F).min(2);
+        if remaining > 0 {
+            self.data[0] = remaining - 1;
             let index_base = 1 + (self.data[0] as usize) * 6;
+            let raw_x = (((self.data[index_base] & 0xF) as u16) << 8)
+                + (self.data[index_base + 1] as u16);
+            let raw_y = (((self.data[index_base + 2] & 0xF) as u16) << 8)
+                + (self.data[index_base + 3] as u16);
+            let (x, y) = self.transform.apply(raw_x, raw_y);
             let p = Point {
                 index: self.data[index_base + 2] >> 4,
                 action: PointAction::from_primitive(self.data[index_base] >> 6),
-                x: (((self.data[index_base] & 0xF) as u16) << 8)
-                    + (self.data[index_base + 1] as u16),
-                y: (((self.data[index_base + 2] & 0xF) as u16) << 8)
-                    + (self.data[index_base + 3] as u16),
+                x,
+                y,
+                weight: self.data[index_base + 4],
+                area: self.data[index_base + 5] >> 4,
             };
             Some(p)
         } else {
@@ -72,8 +86,11 @@ impl<I2C: I2c> Ft6336<I2C> {
 
     /// Get an iterator over current touch events.
     pub fn touch_points_iter(&mut self) -> Result<PointsIter, Error> {
-        let mut buf: [u8; 11] = [0; 11];
+        let mut buf: [u8; 13] = [0; 13];
         self.read_buf(REG_TOUCH_COUNT, &mut buf)?;
-        Ok(PointsIter { data: buf })
+        Ok(PointsIter {
+            data: buf,
+            transform: self.transform,
+        })
     }
 }
@@ -0,0 +1,73 @@
+//! Coordinate transform for panel orientation.
+//!
+//! Touch panels are often mounted rotated or mirrored relative to the display.
+//! A [`Transform`] describes that mounting as a rotation plus independent x/y
+//! inversion, mirroring the `touchscreen-swapped-x-y` / `touchscreen-inverted-x`
+//! / `touchscreen-inverted-y` properties used by Linux touchscreen drivers, and
+//! is applied to every [`Point`](crate::touch::Point) before it is yielded.
+
+/// Panel rotation relative to the display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Rotation {
+    /// No rotation.
+    #[default]
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl Rotation {
+    /// Decomposes the rotation into swap / invert-x / invert-y primitives.
+    const fn primitives(self) -> (bool, bool, bool) {
+        match self {
+            Rotation::Deg0 => (false, false, false),
+            Rotation::Deg90 => (true, true, false),
+            Rotation::Deg180 => (false, true, true),
+            Rotation::Deg270 => (true, false, true),
+        }
+    }
+}
+
+/// Coordinate transform configuration.
+///
+/// The default is the identity transform, leaving coordinates untouched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Transform {
+    /// Panel rotation.
+    pub rotation: Rotation,
+    /// Invert the x axis after rotation.
+    pub invert_x: bool,
+    /// Invert the y axis after rotation.
+    pub invert_y: bool,
+    /// Panel maximum x resolution, used for inversion.
+    pub max_x: u16,
+    /// Panel maximum y resolution, used for inversion.
+    pub max_y: u16,
+}
+
+impl Transform {
+    /// Applies the transform to a raw coordinate pair, returning display-space
+    /// coordinates.
+    pub fn apply(&self, x: u16, y: u16) -> (u16, u16) {
+        let (swap, rot_ix, rot_iy) = self.rotation.primitives();
+        // Swap the axis extents alongside the coordinates so the inversion uses
+        // the post-swap axis maximum; on a non-square panel the swapped x ranges
+        // over `0..max_y` and vice versa.
+        let (mut x, mut y) = if swap { (y, x) } else { (x, y) };
+        let (ext_x, ext_y) = if swap {
+            (self.max_y, self.max_x)
+        } else {
+            (self.max_x, self.max_y)
+        };
+        if rot_ix ^ self.invert_x {
+            x = ext_x.saturating_sub(x);
+        }
+        if rot_iy ^ self.invert_y {
+            y = ext_y.saturating_sub(y);
+        }
+        (x, y)
+    }
+}